@@ -9,11 +9,81 @@
 //! they can directly use the Component trait.
 //!
 
-trait Predictor {
+trait Predictor: Sized {
     fn fit(self, x: impl Iterator<Item = f64>, y: impl Iterator<Item = f64>) -> Self;
     fn predict<'a>(&self, x: impl Iterator<Item = f64> + 'a) -> Box<dyn Iterator<Item = f64> + 'a>;
+
+    /// Fallible variant of `fit`. The default implementation never fails;
+    /// decorators whose transform has a restricted domain (e.g. `ln`)
+    /// override this to validate their input before delegating.
+    fn try_fit(
+        self,
+        x: impl Iterator<Item = f64>,
+        y: impl Iterator<Item = f64>,
+    ) -> Result<Self, FitError> {
+        Ok(self.fit(x, y))
+    }
+
+    /// Fallible variant of `predict`. The default implementation never fails;
+    /// decorators whose transform has a restricted domain (e.g. `ln`)
+    /// override this to validate their input before delegating.
+    fn try_predict<'a>(
+        &self,
+        x: impl Iterator<Item = f64> + 'a,
+    ) -> Result<Box<dyn Iterator<Item = f64> + 'a>, PredictError> {
+        Ok(self.predict(x))
+    }
+}
+
+/// Error produced while fitting a [`Predictor`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FitError {
+    /// Fewer than two data points were supplied.
+    InsufficientData { n: usize },
+    /// All `x` values are equal, so the variance of `x` is zero and the
+    /// least-squares slope is undefined.
+    DegenerateData,
+    /// A log-decorator was asked to take the logarithm of a non-positive value.
+    NonPositiveInput { value: f64 },
+}
+
+impl std::fmt::Display for FitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FitError::InsufficientData { n } => {
+                write!(f, "need at least two data points to fit, got {}", n)
+            }
+            FitError::DegenerateData => {
+                write!(f, "all x values are equal, cannot fit a slope")
+            }
+            FitError::NonPositiveInput { value } => {
+                write!(f, "cannot take the logarithm of non-positive value {}", value)
+            }
+        }
+    }
 }
 
+impl std::error::Error for FitError {}
+
+/// Error produced while predicting with a [`Predictor`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum PredictError {
+    /// A log-decorator was asked to take the logarithm of a non-positive value.
+    NonPositiveInput { value: f64 },
+}
+
+impl std::fmt::Display for PredictError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PredictError::NonPositiveInput { value } => {
+                write!(f, "cannot take the logarithm of non-positive value {}", value)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PredictError {}
+
 #[derive(Default)]
 struct LinearPredictor {
     offset: f64,
@@ -44,6 +114,35 @@ impl Predictor for LinearPredictor {
         let offset = self.offset;
         Box::new(x.map(move |xi| offset + slope * xi))
     }
+
+    fn try_fit(
+        mut self,
+        x: impl Iterator<Item = f64>,
+        y: impl Iterator<Item = f64>,
+    ) -> Result<Self, FitError> {
+        let mut n = 0.0;
+        let mut sum_x = 0.0;
+        let mut sum_y = 0.0;
+        let mut sum_xx = 0.0;
+        let mut sum_xy = 0.0;
+        for (xi, yi) in x.zip(y) {
+            n += 1.0;
+            sum_x += xi;
+            sum_y += yi;
+            sum_xx += xi * xi;
+            sum_xy += xi * yi;
+        }
+        if n < 2.0 {
+            return Err(FitError::InsufficientData { n: n as usize });
+        }
+        let denom = sum_xx * n - sum_x * sum_x;
+        if denom == 0.0 {
+            return Err(FitError::DegenerateData);
+        }
+        self.slope = (sum_xy * n - sum_x * sum_y) / denom;
+        self.offset = (sum_y - self.slope * sum_x) / n;
+        Ok(self)
+    }
 }
 
 struct LogYDecorator<P: Predictor> {
@@ -68,6 +167,32 @@ impl<P: Predictor> Predictor for LogYDecorator<P> {
     fn predict<'a>(&self, x: impl Iterator<Item = f64> + 'a) -> Box<dyn Iterator<Item = f64> + 'a> {
         Box::new(self.decorated_predictor.predict(x).map(f64::exp))
     }
+
+    fn try_fit(
+        mut self,
+        x: impl Iterator<Item = f64>,
+        y: impl Iterator<Item = f64>,
+    ) -> Result<Self, FitError> {
+        let y: Vec<f64> = y.collect();
+        if let Some(&value) = y.iter().find(|&&v| v <= 0.0) {
+            return Err(FitError::NonPositiveInput { value });
+        }
+        let logy = y.into_iter().map(f64::ln);
+        self.decorated_predictor = self.decorated_predictor.try_fit(x, logy)?;
+        Ok(self)
+    }
+
+    fn try_predict<'a>(
+        &self,
+        x: impl Iterator<Item = f64> + 'a,
+    ) -> Result<Box<dyn Iterator<Item = f64> + 'a>, PredictError> {
+        // Delegate via try_predict, not predict: the decorated predictor may
+        // itself need to validate its input domain (e.g. a LogXDecorator
+        // underneath), and falling back to the infallible `predict` chain
+        // would let that inner validation be skipped, reintroducing NaN.
+        let inner = self.decorated_predictor.try_predict(x)?;
+        Ok(Box::new(inner.map(f64::exp)))
+    }
 }
 
 struct LogXDecorator<P: Predictor> {
@@ -93,6 +218,32 @@ impl<P: Predictor> Predictor for LogXDecorator<P> {
         let logx = x.map(f64::ln);
         Box::new(self.decorated_predictor.predict(logx))
     }
+
+    fn try_fit(
+        mut self,
+        x: impl Iterator<Item = f64>,
+        y: impl Iterator<Item = f64>,
+    ) -> Result<Self, FitError> {
+        let x: Vec<f64> = x.collect();
+        if let Some(&value) = x.iter().find(|&&v| v <= 0.0) {
+            return Err(FitError::NonPositiveInput { value });
+        }
+        let logx = x.into_iter().map(f64::ln);
+        self.decorated_predictor = self.decorated_predictor.try_fit(logx, y)?;
+        Ok(self)
+    }
+
+    fn try_predict<'a>(
+        &self,
+        x: impl Iterator<Item = f64> + 'a,
+    ) -> Result<Box<dyn Iterator<Item = f64> + 'a>, PredictError> {
+        let x: Vec<f64> = x.collect();
+        if let Some(&value) = x.iter().find(|&&v| v <= 0.0) {
+            return Err(PredictError::NonPositiveInput { value });
+        }
+        let logx = x.into_iter().map(f64::ln);
+        self.decorated_predictor.try_predict(logx)
+    }
 }
 
 #[test]
@@ -136,3 +287,77 @@ fn predict() {
             .collect::<Vec<_>>()
     );
 }
+
+#[test]
+fn try_fit_insufficient_data() {
+    let result = LinearPredictor::default().try_fit([1.0].into_iter(), [1.0].into_iter());
+    assert_eq!(result.err(), Some(FitError::InsufficientData { n: 1 }));
+}
+
+#[test]
+fn try_fit_degenerate_data() {
+    let x_train = [3.0, 3.0, 3.0];
+    let y_train = [1.0, 2.0, 3.0];
+    let result =
+        LinearPredictor::default().try_fit(x_train.iter().copied(), y_train.iter().copied());
+    assert_eq!(result.err(), Some(FitError::DegenerateData));
+}
+
+#[test]
+fn try_fit_propagates_through_decorators() {
+    let x_train = [3.0, 3.0, 3.0];
+    let y_train = [1.0, 2.0, 3.0];
+    let result = LogXDecorator::new(LogYDecorator::new(LinearPredictor::default()))
+        .try_fit(x_train.iter().copied(), y_train.iter().copied());
+    assert_eq!(result.err(), Some(FitError::DegenerateData));
+}
+
+#[test]
+fn try_fit_rejects_non_positive_y_for_log_y() {
+    let x_train = [1.0, 2.0, 3.0];
+    let y_train = [1.0, 0.0, 3.0];
+    let result = LogYDecorator::new(LinearPredictor::default())
+        .try_fit(x_train.iter().copied(), y_train.iter().copied());
+    assert_eq!(result.err(), Some(FitError::NonPositiveInput { value: 0.0 }));
+}
+
+#[test]
+fn try_fit_rejects_non_positive_x_for_log_x() {
+    let x_train = [1.0, -2.0, 3.0];
+    let y_train = [1.0, 2.0, 3.0];
+    let result = LogXDecorator::new(LinearPredictor::default())
+        .try_fit(x_train.iter().copied(), y_train.iter().copied());
+    assert_eq!(
+        result.err(),
+        Some(FitError::NonPositiveInput { value: -2.0 })
+    );
+}
+
+#[test]
+fn try_predict_rejects_non_positive_x_for_log_x() {
+    let x_train = [2.0, 4.0, 6.0, 8.0];
+    let y_train = [1.0, 2.0, 3.0, 4.0];
+    let predictor = LogXDecorator::new(LinearPredictor::default())
+        .fit(x_train.iter().copied(), y_train.iter().copied());
+
+    let result = predictor.try_predict([1.0, -1.0].into_iter());
+    assert_eq!(
+        result.err(),
+        Some(PredictError::NonPositiveInput { value: -1.0 })
+    );
+}
+
+#[test]
+fn try_predict_propagates_inner_validation_through_log_y() {
+    let x_train = [2.0, 4.0, 6.0, 8.0];
+    let y_train = [1.0, 2.0, 3.0, 4.0];
+    let predictor = LogYDecorator::new(LogXDecorator::new(LinearPredictor::default()))
+        .try_fit(x_train.iter().copied(), y_train.iter().copied())
+        .unwrap();
+
+    let result = predictor.try_predict([1.0, -1.0].into_iter());
+    assert_eq!(
+        result.err(),
+        Some(PredictError::NonPositiveInput { value: -1.0 })
+    );
+}
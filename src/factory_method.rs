@@ -31,18 +31,101 @@ trait Pizza {
     }
 }
 
-trait PizzaStore {
-    fn create_pizza(&self, pizza_type: &str) -> Box<dyn Pizza>;
+/// Entry point into the typestate pipeline below: wraps a freshly created
+/// pizza so that `.prepare()` is the only next step available. Generic over
+/// the pizza's own representation (e.g. `Box<dyn Pizza>`), so the pipeline
+/// is not tied to how a concrete `PizzaStore` produces its pizzas.
+struct PizzaBuilder<P> {
+    pizza: P,
+}
+
+impl<P> PizzaBuilder<P>
+where
+    P: std::ops::DerefMut,
+    P::Target: Pizza,
+{
+    fn new(pizza: P) -> Self {
+        PizzaBuilder { pizza }
+    }
+
+    fn prepare(mut self) -> Prepared<P> {
+        self.pizza.prepare();
+        Prepared { pizza: self.pizza }
+    }
+}
+
+/// A pizza that has been prepared but not yet baked. The only way to obtain
+/// one is [`PizzaBuilder::prepare`], and the only way to make progress from
+/// here is [`Prepared::bake`] — baking twice or cutting before baking is a
+/// compile error, not a runtime bug.
+struct Prepared<P> {
+    pizza: P,
+}
+
+impl<P> Prepared<P>
+where
+    P: std::ops::DerefMut,
+    P::Target: Pizza,
+{
+    fn bake(mut self) -> Baked<P> {
+        self.pizza.bake();
+        Baked { pizza: self.pizza }
+    }
+}
+
+/// A pizza that has been baked but not yet cut. The only way to make
+/// progress from here is [`Baked::cut`].
+struct Baked<P> {
+    pizza: P,
+}
+
+impl<P> Baked<P>
+where
+    P: std::ops::DerefMut,
+    P::Target: Pizza,
+{
+    fn cut(mut self) -> Cut<P> {
+        self.pizza.cut();
+        Cut { pizza: self.pizza }
+    }
+}
+
+/// A pizza that has been cut but not yet boxed. The only way to make
+/// progress from here is [`Cut::boxify`].
+struct Cut<P> {
+    pizza: P,
+}
+
+impl<P> Cut<P>
+where
+    P: std::ops::DerefMut,
+    P::Target: Pizza,
+{
+    fn boxify(mut self) -> Boxed<P> {
+        self.pizza.boxify();
+        Boxed { pizza: self.pizza }
+    }
+}
+
+/// The finished pizza, ready to hand to the customer. This is the end of the
+/// pipeline — there is no further transition, and no way to have reached it
+/// without going through `prepare`, `bake`, and `cut` in order.
+struct Boxed<P> {
+    pizza: P,
+}
 
-    fn order_pizza(&self, pizza_type: &str) -> Box<dyn Pizza> {
-        let mut pizza = self.create_pizza(pizza_type);
+impl<P> Boxed<P> {
+    fn into_pizza(self) -> P {
+        self.pizza
+    }
+}
 
-        pizza.prepare();
-        pizza.bake();
-        pizza.cut();
-        pizza.boxify();
+trait PizzaStore {
+    fn create_pizza(&self, pizza_type: &str) -> Box<dyn Pizza>;
 
-        pizza
+    fn order_pizza(&self, pizza_type: &str) -> Boxed<Box<dyn Pizza>> {
+        let pizza = self.create_pizza(pizza_type);
+        PizzaBuilder::new(pizza).prepare().bake().cut().boxify()
     }
 }
 
@@ -131,6 +214,17 @@ fn test_run() {
     let ny_store = NyPizzaStore;
     let chicago_store = ChicagoPizzaStore;
 
-    ny_store.order_pizza("cheese");
-    chicago_store.order_pizza("cheese");
+    ny_store.order_pizza("cheese").into_pizza();
+    chicago_store.order_pizza("cheese").into_pizza();
+}
+
+#[test]
+fn order_pizza_threads_through_every_stage() {
+    // The fluent chain below is the only path from a freshly created pizza
+    // to a `Boxed` one - there is no way to call `.cut()` before `.bake()`
+    // or `.boxify()` before `.cut()`, because each method is only defined
+    // on the wrapper type produced by the previous stage.
+    let pizza = NyPizzaStore.create_pizza("cheese");
+    let boxed = PizzaBuilder::new(pizza).prepare().bake().cut().boxify();
+    assert_eq!(boxed.into_pizza().name(), "NY Style Sauce and Cheese Pizza");
 }
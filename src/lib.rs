@@ -0,0 +1,9 @@
+#![allow(dead_code)]
+#![allow(clippy::arc_with_non_send_sync)]
+
+mod abstract_factory;
+mod decorator;
+mod factory_method;
+mod observer;
+mod singleton;
+mod strategy;
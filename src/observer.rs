@@ -91,6 +91,100 @@ impl Observer<i32> for ExampleObserver {
     }
 }
 
+/// Async counterpart of [`Subject`]/[`Observer`], for observers that need to
+/// do I/O (logging, network pushes, DB writes) in response to state changes.
+/// Weak-reference registration semantics are unchanged: an observer that has
+/// not been explicitly unregistered but is otherwise no longer referenced is
+/// pruned the next time it is encountered during notification.
+use async_trait::async_trait;
+
+#[async_trait]
+trait AsyncObserver<T: Sync>: Send + Sync {
+    async fn update(&self, state: &T);
+}
+
+#[async_trait]
+trait AsyncSubject<T: Sync + Send> {
+    fn register_observer(&mut self, observer: &Arc<dyn AsyncObserver<T>>);
+    fn unregister_observer(&mut self, observer: &Arc<dyn AsyncObserver<T>>);
+
+    /// Await each observer's update in turn, one after another. Observers are
+    /// visited in the order they are stored; removing an observer can change
+    /// that order, so it is not necessarily registration order.
+    async fn notify_observers(&mut self);
+
+    /// Drive all observers' updates concurrently.
+    async fn notify_observers_concurrent(&mut self);
+
+    fn get_state(&self) -> &T;
+}
+
+#[derive(Default)]
+struct AsyncExampleSubject {
+    observers: Vec<Weak<dyn AsyncObserver<i32>>>,
+    state: i32,
+}
+
+impl AsyncExampleSubject {
+    async fn set_state(&mut self, state: i32) {
+        self.state = state;
+        self.notify_observers().await;
+    }
+}
+
+#[async_trait]
+impl AsyncSubject<i32> for AsyncExampleSubject {
+    fn register_observer(&mut self, observer: &Arc<dyn AsyncObserver<i32>>) {
+        self.observers.push(Arc::downgrade(observer))
+    }
+
+    fn unregister_observer(&mut self, observer: &Arc<dyn AsyncObserver<i32>>) {
+        let observer = Arc::downgrade(observer);
+        if let Some(idx) = self
+            .observers
+            .iter()
+            .position(|obs| Weak::ptr_eq(obs, &observer))
+        {
+            self.observers.swap_remove(idx);
+        }
+    }
+
+    async fn notify_observers(&mut self) {
+        let mut idx = 0;
+        while idx < self.observers.len() {
+            if let Some(observer) = self.observers[idx].upgrade() {
+                observer.update(&self.state).await;
+                idx += 1;
+            } else {
+                self.observers.swap_remove(idx);
+            }
+        }
+    }
+
+    async fn notify_observers_concurrent(&mut self) {
+        self.observers.retain(|obs| obs.strong_count() > 0);
+        let live: Vec<_> = self.observers.iter().filter_map(Weak::upgrade).collect();
+        let state = &self.state;
+        futures::future::join_all(live.iter().map(|obs| obs.update(state))).await;
+    }
+
+    fn get_state(&self) -> &i32 {
+        &self.state
+    }
+}
+
+#[derive(Default)]
+struct AsyncExampleObserver {
+    history: std::sync::Mutex<Vec<i32>>,
+}
+
+#[async_trait]
+impl AsyncObserver<i32> for AsyncExampleObserver {
+    async fn update(&self, state: &i32) {
+        self.history.lock().unwrap().push(*state);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -144,4 +238,50 @@ mod tests {
         let obs2 = Obs { };
         sub.observers.push(&obs2);
     }*/
+
+    #[tokio::test]
+    async fn async_working_example_sequential() {
+        let mut subject = AsyncExampleSubject::default();
+        let obs1 = Arc::new(AsyncExampleObserver::default());
+        let obs2 = Arc::new(AsyncExampleObserver::default());
+
+        let dyn1: Arc<dyn AsyncObserver<_>> = obs1.clone();
+        let dyn2: Arc<dyn AsyncObserver<_>> = obs2.clone();
+
+        subject.set_state(1).await;
+        subject.register_observer(&dyn1);
+        subject.set_state(2).await;
+        subject.register_observer(&dyn2);
+        subject.set_state(3).await;
+        subject.unregister_observer(&dyn1);
+        subject.set_state(4).await;
+
+        assert_eq!(&*obs1.history.lock().unwrap(), &vec![2, 3]);
+        assert_eq!(&*obs2.history.lock().unwrap(), &vec![3, 4]);
+
+        drop(obs2);
+        drop(dyn2);
+        subject.set_state(5).await;
+
+        // no observers left although one of them was not explicitly removed
+        assert_eq!(subject.observers.len(), 0);
+    }
+
+    #[tokio::test]
+    async fn async_working_example_concurrent() {
+        let mut subject = AsyncExampleSubject::default();
+        let obs1 = Arc::new(AsyncExampleObserver::default());
+        let obs2 = Arc::new(AsyncExampleObserver::default());
+
+        let dyn1: Arc<dyn AsyncObserver<_>> = obs1.clone();
+        let dyn2: Arc<dyn AsyncObserver<_>> = obs2.clone();
+        subject.register_observer(&dyn1);
+        subject.register_observer(&dyn2);
+
+        subject.state = 42;
+        subject.notify_observers_concurrent().await;
+
+        assert_eq!(&*obs1.history.lock().unwrap(), &vec![42]);
+        assert_eq!(&*obs2.history.lock().unwrap(), &vec![42]);
+    }
 }